@@ -0,0 +1,27 @@
+// src/commands/bench.rs
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::runner::Runner;
+
+pub async fn execute(day: u8, part: u8, input_path: PathBuf, iterations: u32) -> Result<()> {
+    let input = fs::read_to_string(&input_path)
+        .with_context(|| format!("Failed to read input file {:?}", input_path))?;
+
+    let runner = Runner::new()?;
+    let stats = runner.bench_day(day, part, &input, iterations)?;
+
+    println!(
+        "Day {day:02} Part {part}: min {min:?}, median {median:?}, mean {mean:?}, max {max:?} ({n} iterations)",
+        day = day,
+        part = part,
+        min = stats.min,
+        median = stats.median,
+        mean = stats.mean,
+        max = stats.max,
+        n = stats.iterations,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// src/commands/check.rs
+use anyhow::{bail, Result};
+use std::path::PathBuf;
+
+use crate::runner::{DayOutcome, DayFilter, Runner};
+
+pub async fn execute(workspace: PathBuf, filter: DayFilter) -> Result<()> {
+    let runner = Runner::new()?;
+    let reports = runner.check_all(&workspace, filter)?;
+
+    let mut passed = 0;
+    for report in &reports {
+        let day_str = format!("day{:02}", report.day);
+        match &report.outcome {
+            Ok(outcome) => {
+                if outcome.is_passed() {
+                    passed += 1;
+                    println!("{day_str}: ok");
+                } else if let DayOutcome::Failed { stdout, stderr } = outcome {
+                    println!("{day_str}: FAILED\n{stdout}\n{stderr}");
+                }
+            }
+            Err(err) => println!("{day_str}: could not run `cargo check`: {err}"),
+        }
+    }
+
+    println!("{passed}/{} days passed `cargo check`", reports.len());
+
+    if passed != reports.len() {
+        bail!("one or more days failed `cargo check`");
+    }
+
+    Ok(())
+}
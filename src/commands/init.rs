@@ -8,16 +8,10 @@ pub async fn execute(path: PathBuf) -> Result<()> {
     // Create the workspace directory
     fs::create_dir_all(&path)?;
 
-    // Create workspace Cargo.toml with all day crates as members
+    // Create workspace Cargo.toml with day crates inferred by glob, so the
+    // workspace compiles whether zero or twenty-five days exist
     let workspace_toml = r#"[workspace]
-members = [
-    "runner",".tmp*",
-    "day01", "day02", "day03", "day04", "day05",
-    "day06", "day07", "day08", "day09", "day10",
-    "day11", "day12", "day13", "day14", "day15",
-    "day16", "day17", "day18", "day19", "day20",
-    "day21", "day22", "day23", "day24", "day25"
-]
+members = ["runner", "day*", ".tmp*"]
 resolver = "2"
 
 [workspace.dependencies]
@@ -28,11 +22,6 @@ anyhow = "1.0.75"
     // Create runner crate
     create_runner_crate(&path)?;
 
-    // Create all day crates
-    for day in 1..=25 {
-        create_day_crate(&path, day)?;
-    }
-
     // Create .gitignore
     let gitignore = r#"# Generated by Cargo
 /target/
@@ -113,7 +102,7 @@ anyhow.workspace = true
     Ok(())
 }
 
-fn create_day_crate(workspace_path: &Path, day: u8) -> Result<()> {
+pub(crate) fn create_day_crate(workspace_path: &Path, day: u8) -> Result<()> {
     let day_str = format!("day{:02}", day);
     let day_path = workspace_path.join(&day_str);
     fs::create_dir_all(day_path.join("src"))?;
@@ -201,44 +190,20 @@ mod tests {
         assert!(temp_dir.path().join("runner/src/main.rs").exists());
         assert!(temp_dir.path().join("runner/Cargo.toml").exists());
 
-        // Verify all day crates are created
+        // No day crates should be scaffolded by init - they're created on
+        // demand by `aocr new`
         for day in 1..=25 {
             let day_str = format!("day{:02}", day);
-            let day_path = temp_dir.path().join(&day_str);
-
-            assert!(day_path.exists(), "Day crate {} not created", day_str);
             assert!(
-                day_path.join("src").exists(),
-                "src directory missing for {}",
+                !temp_dir.path().join(&day_str).exists(),
+                "init should not scaffold {}",
                 day_str
             );
-            assert!(
-                day_path.join("src/lib.rs").exists(),
-                "lib.rs missing for {}",
-                day_str
-            );
-            assert!(
-                day_path.join("Cargo.toml").exists(),
-                "Cargo.toml missing for {}",
-                day_str
-            );
-
-            // Verify Cargo.toml contents
-            let cargo_contents = fs::read_to_string(day_path.join("Cargo.toml"))?;
-            assert!(cargo_contents.contains(&format!("name = \"{}\"", day_str)));
-
-            // Verify lib.rs contents
-            let lib_contents = fs::read_to_string(day_path.join("src/lib.rs"))?;
-            assert!(lib_contents.contains("pub fn part1"));
-            assert!(lib_contents.contains("pub fn part2"));
-            assert!(lib_contents.contains(&format!("Day {}", day)));
         }
 
-        // Verify workspace Cargo.toml contains all crates
+        // Verify workspace Cargo.toml infers day crates by glob
         let workspace_contents = fs::read_to_string(temp_dir.path().join("Cargo.toml"))?;
-        for day in 1..=25 {
-            assert!(workspace_contents.contains(&format!("\"day{:02}\"", day)));
-        }
+        assert!(workspace_contents.contains("\"day*\""));
 
         // Verify git repository
         assert!(
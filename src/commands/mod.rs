@@ -0,0 +1,7 @@
+// src/commands/mod.rs
+pub mod bench;
+pub mod check;
+pub mod init;
+pub mod new;
+pub mod run;
+pub mod test;
@@ -0,0 +1,52 @@
+// src/commands/new.rs
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+use super::init::create_day_crate;
+
+pub async fn execute(path: PathBuf, day: u8) -> Result<()> {
+    let day_str = format!("day{:02}", day);
+    let day_path = path.join(&day_str);
+    if day_path.exists() {
+        anyhow::bail!("{} already exists at {:?}", day_str, day_path);
+    }
+
+    create_day_crate(&path, day).context("Failed to scaffold day crate")?;
+
+    println!("Created {} at {:?}", day_str, day_path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_new_command() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+
+        execute(temp_dir.path().to_path_buf(), 7).await?;
+
+        let day_path = temp_dir.path().join("day07");
+        assert!(day_path.join("Cargo.toml").exists());
+        assert!(day_path.join("src/lib.rs").exists());
+
+        let lib_contents = fs::read_to_string(day_path.join("src/lib.rs"))?;
+        assert!(lib_contents.contains("Day 7"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_new_command_rejects_existing_day() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+
+        execute(temp_dir.path().to_path_buf(), 3).await?;
+        let result = execute(temp_dir.path().to_path_buf(), 3).await;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+}
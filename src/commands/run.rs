@@ -0,0 +1,18 @@
+// src/commands/run.rs
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::runner::Runner;
+
+pub async fn execute(day: u8, part: u8, input_path: PathBuf) -> Result<()> {
+    let input = fs::read_to_string(&input_path)
+        .with_context(|| format!("Failed to read input file {:?}", input_path))?;
+
+    let runner = Runner::new()?;
+    let result = runner.run_day(day, part, &input)?;
+
+    println!("Day {day:02} Part {part}: {result}");
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+// src/main.rs
+mod commands;
+mod runner;
+
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Environment variable external `aocr-*` plugins can read to find the
+/// workspace root, mirroring how Cargo passes `CARGO` to its subcommands.
+const WORKSPACE_ENV_VAR: &str = "AOCR_WORKSPACE";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let Some(subcommand) = args.next() else {
+        bail!("Usage: aocr <init|new|run|bench|check|test|...> [args]");
+    };
+    let rest: Vec<String> = args.collect();
+
+    match subcommand.as_str() {
+        "init" => {
+            let path = rest.first().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("."));
+            commands::init::execute(path).await
+        }
+        "new" => {
+            let day: u8 = rest
+                .first()
+                .context("Usage: aocr new <day>")?
+                .parse()
+                .context("day must be a number between 1 and 25")?;
+            commands::new::execute(PathBuf::from("."), day).await
+        }
+        "run" => {
+            let day: u8 = rest
+                .first()
+                .context("Usage: aocr run <day> <part> <input>")?
+                .parse()
+                .context("day must be a number between 1 and 25")?;
+            let part: u8 = rest
+                .get(1)
+                .context("Usage: aocr run <day> <part> <input>")?
+                .parse()
+                .context("part must be 1 or 2")?;
+            let input = PathBuf::from(
+                rest.get(2)
+                    .context("Usage: aocr run <day> <part> <input>")?,
+            );
+            commands::run::execute(day, part, input).await
+        }
+        "bench" => {
+            let day: u8 = rest
+                .first()
+                .context("Usage: aocr bench <day> <part> <input> [iterations]")?
+                .parse()
+                .context("day must be a number between 1 and 25")?;
+            let part: u8 = rest
+                .get(1)
+                .context("Usage: aocr bench <day> <part> <input> [iterations]")?
+                .parse()
+                .context("part must be 1 or 2")?;
+            let input = PathBuf::from(
+                rest.get(2)
+                    .context("Usage: aocr bench <day> <part> <input> [iterations]")?,
+            );
+            let iterations: u32 = rest
+                .get(3)
+                .map(|s| s.parse())
+                .transpose()
+                .context("iterations must be a number")?
+                .unwrap_or(10);
+            commands::bench::execute(day, part, input, iterations).await
+        }
+        "check" => {
+            let filter = rest
+                .first()
+                .map(|s| s.parse())
+                .transpose()?
+                .unwrap_or(runner::DayFilter::All);
+            commands::check::execute(PathBuf::from("."), filter).await
+        }
+        "test" => {
+            let filter = rest
+                .first()
+                .map(|s| s.parse())
+                .transpose()?
+                .unwrap_or(runner::DayFilter::All);
+            commands::test::execute(PathBuf::from("."), filter).await
+        }
+        other => dispatch_external(other, &rest),
+    }
+}
+
+/// Resolve unknown subcommands to an `aocr-<subcommand>` executable on
+/// `$PATH`, forwarding the remaining args and the workspace path via
+/// [`WORKSPACE_ENV_VAR`]. This follows Cargo's model of falling back to
+/// external `cargo-<name>` binaries, letting the community ship plugins
+/// (e.g. `aocr-submit`, `aocr-leaderboard`, `aocr-viz`) without patching
+/// this crate.
+fn dispatch_external(subcommand: &str, args: &[String]) -> Result<()> {
+    let binary = format!("aocr-{subcommand}");
+    let workspace = env::current_dir().context("Failed to determine workspace path")?;
+
+    let status = Command::new(&binary)
+        .args(args)
+        .env(WORKSPACE_ENV_VAR, &workspace)
+        .status()
+        .with_context(|| format!("Unknown subcommand `{subcommand}` and no `{binary}` found on PATH"))?;
+
+    if !status.success() {
+        bail!("`{binary}` exited with {status}");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispatch_external_missing_binary() {
+        let result = dispatch_external("definitely-not-a-real-aocr-plugin", &[]);
+        assert!(result.is_err());
+    }
+}
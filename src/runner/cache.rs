@@ -0,0 +1,196 @@
+// src/runner/cache.rs
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Where the answer cache lives, relative to the workspace root. Kept
+/// inside `target/` alongside Cargo's own build artifacts so `cargo clean`
+/// sweeps it away too.
+const CACHE_FILE_NAME: &str = "aocr-cache.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct Cache {
+    entries: HashMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    input_hash: String,
+    source_fingerprint: String,
+    dep_paths: Vec<String>,
+    answer: usize,
+}
+
+impl Cache {
+    /// `workspace_target` is the workspace's shared `target/` dir — the same
+    /// one `aoc-runner`'s own build artifacts land in, not a path relative
+    /// to the process's ambient cwd, since `Runner::new_in` lets the runner
+    /// project be nested somewhere other than the cwd.
+    pub(super) fn path(workspace_target: &Path) -> PathBuf {
+        workspace_target.join(CACHE_FILE_NAME)
+    }
+
+    pub(super) fn load(path: &Path) -> Self {
+        fs_read(path)
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub(super) fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, contents).context("Failed to write answer cache")
+    }
+
+    /// Returns the cached answer for `key` if the input and every
+    /// previously recorded dependency file still hash the same way.
+    pub(super) fn lookup(&self, key: &str, input_hash: &str) -> Option<usize> {
+        let entry = self.entries.get(key)?;
+        if entry.input_hash != input_hash {
+            return None;
+        }
+        let dep_paths: Vec<PathBuf> = entry.dep_paths.iter().map(PathBuf::from).collect();
+        let fingerprint = fingerprint_sources(&dep_paths).ok()?;
+        if fingerprint == entry.source_fingerprint {
+            Some(entry.answer)
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn store(
+        &mut self,
+        key: String,
+        input_hash: String,
+        dep_paths: &[PathBuf],
+        answer: usize,
+    ) -> Result<()> {
+        let source_fingerprint = fingerprint_sources(dep_paths)?;
+        self.entries.insert(
+            key,
+            CacheEntry {
+                input_hash,
+                source_fingerprint,
+                dep_paths: dep_paths
+                    .iter()
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .collect(),
+                answer,
+            },
+        );
+        Ok(())
+    }
+}
+
+fn fs_read(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+}
+
+/// Hash an arbitrary string (e.g. the puzzle input) into the same 16-digit
+/// hex form that source fingerprints use. Only the string's contents are
+/// hashed; unlike `fingerprint_sources`, no path or mtime goes in.
+pub(super) fn hash_input(input: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    input.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Parse a Cargo `.d` dep-info file into the list of source paths it
+/// depends on. Dep-info files are `target: dep dep dep`, optionally
+/// continued across lines with a trailing `\`.
+pub(super) fn parse_dep_info(path: &Path) -> Result<Vec<PathBuf>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read dep-info file {:?}", path))?;
+    let joined = contents.replace("\\\n", " ");
+
+    let mut paths = Vec::new();
+    for line in joined.lines() {
+        let Some((_target, deps)) = line.split_once(':') else {
+            continue;
+        };
+        paths.extend(deps.split_whitespace().map(PathBuf::from));
+    }
+    paths.sort();
+    paths.dedup();
+    Ok(paths)
+}
+
+/// Fingerprint a set of source files by hashing each one's contents and
+/// modification time, so either an edit or a touch invalidates the cache.
+fn fingerprint_sources(paths: &[PathBuf]) -> Result<String> {
+    let mut hasher = DefaultHasher::new();
+    for path in paths {
+        path.hash(&mut hasher);
+        let contents =
+            std::fs::read(path).with_context(|| format!("Failed to read source file {:?}", path))?;
+        contents.hash(&mut hasher);
+        if let Ok(modified) = std::fs::metadata(path).and_then(|meta| meta.modified()) {
+            modified.hash(&mut hasher);
+        }
+    }
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_dep_info_joins_continuations() -> Result<()> {
+        let dir = TempDir::new()?;
+        let dep_info = dir.path().join("aoc-runner.d");
+        std::fs::write(&dep_info, "target/debug/aoc-runner: src/main.rs \\\n  ../day01/src/lib.rs\n")?;
+
+        let paths = parse_dep_info(&dep_info)?;
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("../day01/src/lib.rs"), PathBuf::from("src/main.rs")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cache_hit_after_store_and_miss_after_edit() -> Result<()> {
+        let dir = TempDir::new()?;
+        let source = dir.path().join("lib.rs");
+        std::fs::write(&source, "pub fn part1() {}")?;
+
+        let mut cache = Cache::default();
+        let input_hash = hash_input("test input");
+        cache.store(
+            "day01-part1".to_string(),
+            input_hash.clone(),
+            std::slice::from_ref(&source),
+            42,
+        )?;
+
+        assert_eq!(cache.lookup("day01-part1", &input_hash), Some(42));
+
+        // Touching the source file should invalidate the cached answer
+        std::fs::write(&source, "pub fn part1() { 1 }")?;
+        assert_eq!(cache.lookup("day01-part1", &input_hash), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cache_miss_on_different_input() -> Result<()> {
+        let dir = TempDir::new()?;
+        let source = dir.path().join("lib.rs");
+        std::fs::write(&source, "pub fn part1() {}")?;
+
+        let mut cache = Cache::default();
+        let input_hash = hash_input("test input");
+        cache.store("day01-part1".to_string(), input_hash, &[source], 42)?;
+
+        assert_eq!(cache.lookup("day01-part1", &hash_input("other input")), None);
+        Ok(())
+    }
+}
@@ -1,45 +1,162 @@
 // src/runner/mod.rs
+mod cache;
+mod error;
+
+pub use error::{DayFilter, DayOutcome, RunError};
+
 use anyhow::{Context, Result};
 use std::fs;
+use std::path::Path;
 use std::process::Command;
+use std::thread;
+use std::time::Duration;
 use tempfile::TempDir;
 
+/// One day's outcome from `Runner::check_all`/`Runner::test_all`.
+pub struct DayReport {
+    pub day: u8,
+    pub outcome: Result<DayOutcome>,
+}
+
 pub struct Runner {
     runner_dir: TempDir,
 }
 
+/// Wall-clock timing statistics for `Runner::bench_day`, in the style of
+/// `cargo bench`'s per-benchmark summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+/// Prefix the generated runner's `main.rs` prints before each iteration's
+/// elapsed nanoseconds, so `bench_day` can parse timings out of stdout
+/// without scraping the solution's own `println!` output.
+const TIMING_PREFIX: &str = "AOCR_TIMING_NS:";
+
 impl Runner {
     pub fn new() -> Result<Self> {
-        let runner_dir = TempDir::new_in(".")?;
+        Self::new_in(Path::new("."))
+    }
+
+    /// Like [`Runner::new`], but nests the ephemeral runner project under
+    /// `workspace_path` instead of the process's current directory. Lets
+    /// tests exercise the real nested-tempdir layout without mutating
+    /// process-wide state like the cwd.
+    pub fn new_in(workspace_path: &Path) -> Result<Self> {
+        let runner_dir = TempDir::new_in(workspace_path)?;
 
         Ok(Self { runner_dir })
     }
 
-    pub fn check_day(&self, day: u8) -> Result<String> {
+    /// Run `cargo check -p dayNN` against the workspace rooted at
+    /// `workspace_path`. An `Err` means cargo itself could not be launched;
+    /// `Ok(DayOutcome::Failed)` means cargo ran but the day crate has
+    /// compile errors, carried in full so the CLI can print them as-is.
+    pub fn check_day(&self, workspace_path: &Path, day: u8) -> Result<DayOutcome> {
         let output = Command::new("cargo")
             .arg("check")
             .arg("-p")
             .arg(format!("day{:02}", day))
-            .output()?;
+            .current_dir(workspace_path)
+            .output()
+            .context("Failed to launch `cargo check`")?;
 
-        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        Ok(Self::day_outcome(output))
     }
 
-    pub fn test_day(&self, day: u8) -> Result<String> {
+    /// Run `cargo test -p dayNN` against the workspace rooted at
+    /// `workspace_path`. An `Err` means cargo itself could not be launched;
+    /// `Ok(DayOutcome::Failed)` means cargo ran but a test in the day crate
+    /// failed, carried in full so the CLI can print it as-is.
+    pub fn test_day(&self, workspace_path: &Path, day: u8) -> Result<DayOutcome> {
         let output = Command::new("cargo")
             .arg("test")
             .arg("-p")
             .arg(format!("day{:02}", day))
-            .output()?;
+            .current_dir(workspace_path)
+            .output()
+            .context("Failed to launch `cargo test`")?;
 
-        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        Ok(Self::day_outcome(output))
+    }
+
+    fn day_outcome(output: std::process::Output) -> DayOutcome {
+        if output.status.success() {
+            DayOutcome::Passed
+        } else {
+            let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+            let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+            DayOutcome::Failed { stdout, stderr }
+        }
+    }
+
+    /// Run `cargo check -p dayNN` for every day matching `filter` that
+    /// exists under `workspace_path`, concurrently, one thread per day.
+    pub fn check_all(&self, workspace_path: &Path, filter: DayFilter) -> Result<Vec<DayReport>> {
+        self.run_all(workspace_path, filter, Self::check_day)
+    }
+
+    /// Run `cargo test -p dayNN` for every day matching `filter` that
+    /// exists under `workspace_path`, concurrently, one thread per day.
+    pub fn test_all(&self, workspace_path: &Path, filter: DayFilter) -> Result<Vec<DayReport>> {
+        self.run_all(workspace_path, filter, Self::test_day)
+    }
+
+    fn run_all(
+        &self,
+        workspace_path: &Path,
+        filter: DayFilter,
+        f: fn(&Self, &Path, u8) -> Result<DayOutcome>,
+    ) -> Result<Vec<DayReport>> {
+        let days: Vec<u8> = discover_days(workspace_path)?
+            .into_iter()
+            .filter(|day| filter.matches(*day))
+            .collect();
+
+        let mut reports = thread::scope(|scope| {
+            let handles: Vec<_> = days
+                .into_iter()
+                .map(|day| scope.spawn(move || DayReport { day, outcome: f(self, workspace_path, day) }))
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("day check/test thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        reports.sort_by_key(|report| report.day);
+        Ok(reports)
     }
 
     pub fn run_day(&self, day: u8, part: u8, input: &str) -> Result<usize> {
         // Create temporary runner project
         let day_str = format!("day{:02}", day);
         let runner_path = self.runner_dir.path();
-        dbg!(&runner_path);
+
+        // `aoc-runner` is itself a workspace member (see the `.tmp*` glob in
+        // the generated workspace manifest), so Cargo writes its artifacts
+        // to the shared workspace `target/` dir, not a private one nested
+        // under `runner_path`.
+        let workspace_target = runner_path
+            .parent()
+            .expect("runner_dir is created directly under the workspace root")
+            .join("target");
+
+        let cache_path = cache::Cache::path(&workspace_target);
+        let cache_key = format!("{day_str}-part{part}");
+        let input_hash = cache::hash_input(input);
+        let mut cache = cache::Cache::load(&cache_path);
+
+        if let Some(answer) = cache.lookup(&cache_key, &input_hash) {
+            return Ok(answer);
+        }
+
         // Write input file
         let input_path = runner_path.join("input.txt");
         fs::write(&input_path, input)?;
@@ -73,28 +190,194 @@ edition = "2021"
         fs::write(runner_path.join("src").join("main.rs"), main_rs)?;
 
         // Build and run
-        Command::new("cargo")
+        let build_output = Command::new("cargo")
             .arg("build")
             .current_dir(runner_path)
             .output()
-            .context("Failed to build runner")?;
+            .context("Failed to launch `cargo build`")?;
+
+        if !build_output.status.success() {
+            return Err(RunError::BuildFailed {
+                day,
+                stderr: String::from_utf8_lossy(&build_output.stderr).into_owned(),
+            }
+            .into());
+        }
 
         let output = Command::new("cargo")
             .arg("run")
             .current_dir(runner_path)
             .output()
-            .context("Failed to run solution")?;
+            .context("Failed to launch `cargo run`")?;
+
+        if !output.status.success() {
+            return Err(RunError::RunFailed {
+                day,
+                part,
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            }
+            .into());
+        }
 
         let result_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        let err_str = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        dbg!(&result_str);
-        dbg!(&err_str);
-        result_str
-            .parse()
-            .context("Failed to parse result as usize")
+        let answer: usize = result_str.parse().map_err(|_| {
+            anyhow::Error::from(RunError::UnparseableAnswer {
+                day,
+                part,
+                raw: result_str,
+            })
+        })?;
+
+        // Record the fingerprint of everything cargo says this binary
+        // depends on, so the next identical run on this `Runner` can skip
+        // the build entirely. The recorded paths include this runner's own
+        // temporary project, which is deleted when the `Runner` is dropped,
+        // so a later `Runner` never gets a hit from these entries.
+        let dep_info_path = workspace_target.join("debug/aoc-runner.d");
+        if let Ok(dep_paths) = cache::parse_dep_info(&dep_info_path) {
+            if cache.store(cache_key, input_hash, &dep_paths, answer).is_ok() {
+                let _ = cache.save(&cache_path);
+            }
+        }
+
+        Ok(answer)
+    }
+
+    /// Build `dayNN` in release mode and run `partN` `iterations` times,
+    /// discarding a warm-up iteration, returning min/median/mean/max
+    /// wall-clock timings the way `cargo bench` reports per-benchmark
+    /// numbers.
+    pub fn bench_day(&self, day: u8, part: u8, input: &str, iterations: u32) -> Result<BenchStats> {
+        if iterations == 0 {
+            anyhow::bail!("iterations must be at least 1, got 0");
+        }
+
+        let day_str = format!("day{:02}", day);
+        let runner_path = self.runner_dir.path();
+
+        // Write input file
+        let input_path = runner_path.join("input.txt");
+        fs::write(&input_path, input)?;
+
+        // Create Cargo.toml
+        let cargo_toml = format!(
+            r#"[package]
+name = "aoc-runner"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+{} = {{ path = "../{}" }}
+"#,
+            day_str, day_str
+        );
+        fs::write(runner_path.join("Cargo.toml"), cargo_toml)?;
+
+        // Create src directory
+        fs::create_dir_all(runner_path.join("src"))?;
+
+        // Create main.rs: run one discarded warm-up iteration, then
+        // `iterations` timed iterations, printing each elapsed duration on
+        // its own line for the parent process to parse.
+        let main_rs = format!(
+            r#"fn main() {{
+    let input = include_str!("../input.txt");
+
+    // Warm-up iteration, discarded
+    let _ = {day_str}::part{part}(input);
+
+    for _ in 0..{iterations} {{
+        let start = std::time::Instant::now();
+        let result = {day_str}::part{part}(input);
+        let elapsed = start.elapsed();
+        println!("{{}}", result);
+        println!("{prefix}{{}}", elapsed.as_nanos());
+    }}
+}}"#,
+            day_str = day_str,
+            part = part,
+            iterations = iterations,
+            prefix = TIMING_PREFIX,
+        );
+        fs::write(runner_path.join("src").join("main.rs"), main_rs)?;
+
+        // Build and run in release mode
+        let build_output = Command::new("cargo")
+            .arg("build")
+            .arg("--release")
+            .current_dir(runner_path)
+            .output()
+            .context("Failed to launch `cargo build`")?;
+
+        if !build_output.status.success() {
+            return Err(RunError::BuildFailed {
+                day,
+                stderr: String::from_utf8_lossy(&build_output.stderr).into_owned(),
+            }
+            .into());
+        }
+
+        let output = Command::new("cargo")
+            .arg("run")
+            .arg("--release")
+            .current_dir(runner_path)
+            .output()
+            .context("Failed to run solution")?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut samples: Vec<Duration> = stdout
+            .lines()
+            .filter_map(|line| line.strip_prefix(TIMING_PREFIX))
+            .filter_map(|ns| ns.trim().parse::<u64>().ok())
+            .map(Duration::from_nanos)
+            .collect();
+
+        if samples.len() != iterations as usize {
+            anyhow::bail!(
+                "Expected {} timing samples, got {}",
+                iterations,
+                samples.len()
+            );
+        }
+
+        samples.sort();
+        let min = samples[0];
+        let max = samples[samples.len() - 1];
+        let median = samples[samples.len() / 2];
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+
+        Ok(BenchStats {
+            iterations,
+            min,
+            median,
+            mean,
+            max,
+        })
     }
 }
 
+/// Find every existing `dayNN` crate directory directly under
+/// `workspace_path`, the same naming convention `init`/`new` scaffold.
+fn discover_days(workspace_path: &Path) -> Result<Vec<u8>> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir(workspace_path)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let name = entry.file_name();
+        let Some(rest) = name.to_string_lossy().strip_prefix("day").map(str::to_owned) else {
+            continue;
+        };
+        if let Ok(day) = rest.parse::<u8>() {
+            days.push(day);
+        }
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,31 +422,160 @@ pub fn part2(input: &str) -> usize {
         Ok(())
     }
 
+    /// Exercises the real `Runner::new()` layout, with the ephemeral runner
+    /// project nested inside the workspace root the way production code
+    /// actually lays things out (`Runner::new_in` instead of `Runner::new`
+    /// here only so the test doesn't have to mutate the whole process's
+    /// cwd). This is the shape that needs the generated workspace
+    /// manifest's `".tmp*"` members entry (see `commands::init::execute`)
+    /// to avoid Cargo's "current package believes it's in a workspace when
+    /// it's not" error. Also covers both parts, the way the old
+    /// (non-nested) `test_run_day` used to before it was folded in here.
     #[test]
-    fn test_run_day() -> Result<()> {
-        // Create a temporary workspace
+    fn test_run_day_nested_under_workspace_root() -> Result<()> {
         let workspace = TempDir::new()?;
         setup_test_day(workspace.path(), 1)?;
 
-        // Create workspace Cargo.toml
+        // Mirror the manifest `aocr init` generates, including the ".tmp*"
+        // entry that covers the nested runner project.
         let workspace_toml = r#"[workspace]
-members = ["day*"]
+members = ["day*", ".tmp*"]
 resolver = "2"
 "#;
         fs::write(workspace.path().join("Cargo.toml"), workspace_toml)?;
 
-        // Initialize runner
+        let runner = Runner::new_in(workspace.path())?;
+
+        let part1 = runner.run_day(1, 1, "test input")?;
+        assert_eq!(part1, 42);
+
+        let part2 = runner.run_day(1, 2, "test input")?;
+        assert_eq!(part2, 84);
+
+        Ok(())
+    }
+
+    /// Regression test for the dep-info path: once `aoc-runner` is a real
+    /// workspace member (nested under the workspace root, not given its own
+    /// private `target/`), Cargo writes its dep-info to the shared
+    /// workspace `target/debug/`. Proves the cache actually gets populated
+    /// and then used on the next call (rather than silently failing to find
+    /// the dep-info and rebuilding every time) by checking the built
+    /// binary's mtime doesn't change on the second, cache-hit call.
+    #[test]
+    fn test_run_day_populates_and_reuses_cache_in_nested_workspace() -> Result<()> {
+        let workspace = TempDir::new()?;
+        setup_test_day(workspace.path(), 1)?;
+
+        let workspace_toml = r#"[workspace]
+members = ["day*", ".tmp*"]
+resolver = "2"
+"#;
+        fs::write(workspace.path().join("Cargo.toml"), workspace_toml)?;
+
+        let runner = Runner::new_in(workspace.path())?;
+        let first = runner.run_day(1, 1, "test input")?;
+
+        let workspace_target = workspace.path().join("target");
+        assert!(
+            workspace_target.join("debug/aoc-runner.d").exists(),
+            "expected cargo's dep-info to land in the shared workspace target dir"
+        );
+        assert!(
+            workspace_target.join("aocr-cache.json").exists(),
+            "expected the answer cache to be written after a successful run"
+        );
+
+        let binary_path = workspace_target.join("debug/aoc-runner");
+        let mtime_after_build = fs::metadata(&binary_path)?.modified()?;
+
+        let second = runner.run_day(1, 1, "test input")?;
+        let mtime_after_cache_hit = fs::metadata(&binary_path)?.modified()?;
+        assert_eq!(
+            mtime_after_build, mtime_after_cache_hit,
+            "a cache hit should answer without rebuilding the runner binary"
+        );
+
+        assert_eq!(first, 42);
+        assert_eq!(second, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bench_day() -> Result<()> {
+        // Create a temporary workspace, nesting the runner dir under it the
+        // way `Runner::new()` really does, so the generated `aoc-runner`
+        // crate's `../day01` dependency path actually resolves.
+        let workspace = TempDir::new()?;
+        setup_test_day(workspace.path(), 1)?;
+
+        let workspace_toml = r#"[workspace]
+members = ["day*", ".tmp*"]
+resolver = "2"
+"#;
+        fs::write(workspace.path().join("Cargo.toml"), workspace_toml)?;
+
+        let runner = Runner::new_in(workspace.path())?;
+
+        let stats = runner.bench_day(1, 1, "test input", 5)?;
+        assert_eq!(stats.iterations, 5);
+        assert!(stats.min <= stats.median);
+        assert!(stats.median <= stats.max);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bench_day_rejects_zero_iterations() -> Result<()> {
         let runner = Runner {
             runner_dir: TempDir::new()?,
         };
 
-        // Test part 1
-        let result = runner.run_day(1, 1, "test input")?;
-        assert_eq!(result, 42);
+        assert!(
+            runner.bench_day(1, 1, "test input", 0).is_err(),
+            "bench_day should reject 0 iterations instead of indexing an empty samples Vec"
+        );
 
-        // Test part 2
-        let result = runner.run_day(1, 2, "test input")?;
-        assert_eq!(result, 84);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bench_day_reports_build_failure() -> Result<()> {
+        // Create a temporary workspace with a day crate that fails to compile
+        let workspace = TempDir::new()?;
+        let day_path = workspace.path().join("day01");
+        fs::create_dir_all(day_path.join("src"))?;
+        fs::write(
+            day_path.join("Cargo.toml"),
+            r#"[package]
+name = "day01"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+"#,
+        )?;
+        fs::write(day_path.join("src").join("lib.rs"), "this is not valid rust")?;
+
+        // Nest the runner under the workspace (with the ".tmp*" member entry)
+        // so `../day01` resolves and the build actually reaches the bad crate.
+        let workspace_toml = r#"[workspace]
+members = ["day*", ".tmp*"]
+resolver = "2"
+"#;
+        fs::write(workspace.path().join("Cargo.toml"), workspace_toml)?;
+
+        let runner = Runner::new_in(workspace.path())?;
+
+        let err = runner
+            .bench_day(1, 1, "test input", 5)
+            .expect_err("a day crate with a compile error should fail to bench");
+        assert!(
+            err.downcast_ref::<RunError>()
+                .is_some_and(|e| matches!(e, RunError::BuildFailed { day: 1, .. })),
+            "expected a RunError::BuildFailed, got: {err}"
+        );
 
         Ok(())
     }
@@ -187,12 +599,88 @@ resolver = "2"
         };
 
         // Test cargo check
-        let check_output = runner.check_day(1)?;
-        assert!(!check_output.contains("error"));
+        let check_outcome = runner.check_day(workspace.path(), 1)?;
+        assert!(check_outcome.is_passed());
 
         // Test cargo test
-        let test_output = runner.test_day(1)?;
-        assert!(!test_output.contains("failed"));
+        let test_outcome = runner.test_day(workspace.path(), 1)?;
+        assert!(test_outcome.is_passed());
+
+        Ok(())
+    }
+
+    /// `check_day` run against a workspace that has no `dayNN` package at
+    /// all: cargo itself launches, so the unresolvable `-p day01` comes back
+    /// as `Ok(DayOutcome::Failed)` rather than an `Err`. The test binary's
+    /// own cwd has no `day01` package either, so this would also pass if
+    /// `.current_dir` were dropped; `test_check_and_test_day`, which only
+    /// passes when cargo runs inside the given workspace, catches that.
+    #[test]
+    fn test_check_day_runs_against_given_workspace_not_ambient_cwd() -> Result<()> {
+        let empty_workspace = TempDir::new()?;
+        fs::write(
+            empty_workspace.path().join("Cargo.toml"),
+            "[workspace]\nmembers = []\nresolver = \"2\"\n",
+        )?;
+
+        let runner = Runner {
+            runner_dir: TempDir::new()?,
+        };
+
+        let outcome = runner.check_day(empty_workspace.path(), 1)?;
+        assert!(
+            !outcome.is_passed(),
+            "expected `cargo check -p day01` to fail to resolve in a workspace with no day crates"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_discover_days() -> Result<()> {
+        let workspace = TempDir::new()?;
+        setup_test_day(workspace.path(), 1)?;
+        setup_test_day(workspace.path(), 3)?;
+        fs::create_dir_all(workspace.path().join("runner"))?;
+
+        let days = discover_days(workspace.path())?;
+        assert_eq!(days, vec![1, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_day_filter_matches() {
+        assert!(DayFilter::Single(5).matches(5));
+        assert!(!DayFilter::Single(5).matches(6));
+        assert!(DayFilter::Range(1, 3).matches(2));
+        assert!(!DayFilter::Range(1, 3).matches(4));
+        assert!(DayFilter::All.matches(25));
+    }
+
+    #[test]
+    fn test_check_all() -> Result<()> {
+        let workspace = TempDir::new()?;
+        setup_test_day(workspace.path(), 1)?;
+        setup_test_day(workspace.path(), 2)?;
+
+        let workspace_toml = r#"[workspace]
+members = ["day*"]
+resolver = "2"
+"#;
+        fs::write(workspace.path().join("Cargo.toml"), workspace_toml)?;
+
+        let runner = Runner {
+            runner_dir: TempDir::new()?,
+        };
+
+        let reports = runner.check_all(workspace.path(), DayFilter::All)?;
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].day, 1);
+        assert_eq!(reports[1].day, 2);
+        for report in &reports {
+            assert!(report.outcome.as_ref().unwrap().is_passed());
+        }
 
         Ok(())
     }